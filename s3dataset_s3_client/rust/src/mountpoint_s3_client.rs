@@ -1,8 +1,17 @@
 use std::sync::Arc;
 
-use mountpoint_s3_client::config::{EndpointConfig, S3ClientAuthConfig, S3ClientConfig};
+use mountpoint_s3_client::config::{AddressingStyle, EndpointConfig, S3ClientAuthConfig, S3ClientConfig, Uri};
 use mountpoint_s3_client::types::PutObjectParams;
 use mountpoint_s3_client::{ObjectClient, S3CrtClient};
+use mountpoint_s3_crt::auth::credentials::{
+    CredentialsProvider, CredentialsProviderChainDefaultOptions, CredentialsProviderStsOptions,
+};
+use mountpoint_s3_crt::common::allocator::Allocator;
+use mountpoint_s3_crt::io::channel_bootstrap::{ClientBootstrap, ClientBootstrapOptions};
+use mountpoint_s3_crt::io::event_loop::EventLoopGroup;
+use mountpoint_s3_crt::io::host_resolver::{HostResolver, HostResolverDefaultOptions};
+use mountpoint_s3_crt::tls::{TlsContext, TlsContextOptions};
+use pyo3::exceptions::PyValueError;
 use pyo3::types::PyTuple;
 use pyo3::{pyclass, pymethods, PyRef, PyResult, ToPyObject};
 
@@ -26,28 +35,57 @@ pub struct MountpointS3Client {
     profile: Option<String>,
     #[pyo3(get)]
     no_sign_request: bool,
+    #[pyo3(get)]
+    endpoint_url: Option<String>,
+    #[pyo3(get)]
+    force_path_style: bool,
+    #[pyo3(get)]
+    role_arn: Option<String>,
+    #[pyo3(get)]
+    external_id: Option<String>,
+    #[pyo3(get)]
+    session_name: Option<String>,
+    #[pyo3(get)]
+    role_session_duration_secs: Option<u64>,
+    #[pyo3(get)]
+    max_attempts: Option<u32>,
 }
 
 #[pymethods]
 impl MountpointS3Client {
     #[new]
-    #[pyo3(signature = (region, throughput_target_gbps=10.0, part_size=8*1024*1024, profile=None, no_sign_request=false))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (region, throughput_target_gbps=10.0, part_size=8*1024*1024, profile=None, no_sign_request=false, endpoint_url=None, force_path_style=false, role_arn=None, external_id=None, session_name=None, role_session_duration_secs=None, max_attempts=None))]
     pub fn new_s3_client(
         region: String,
         throughput_target_gbps: f64,
         part_size: usize,
         profile: Option<String>,
         no_sign_request: bool,
+        endpoint_url: Option<String>,
+        force_path_style: bool,
+        role_arn: Option<String>,
+        external_id: Option<String>,
+        session_name: Option<String>,
+        role_session_duration_secs: Option<u64>,
+        max_attempts: Option<u32>,
     ) -> PyResult<Self> {
         /*
         TODO - Mountpoint has logic for guessing based on instance type.
          It may be worth having similar logic if we want to exceed 10Gbps reading for larger instances
         */
 
-        let endpoint_config = EndpointConfig::new(&region);
-        let auth_config = auth_config(profile.as_deref(), no_sign_request);
+        let endpoint_config = endpoint_config(&region, endpoint_url.as_deref(), force_path_style)?;
+        let auth_config = auth_config(
+            profile.as_deref(),
+            no_sign_request,
+            role_arn.as_deref(),
+            external_id.as_deref(),
+            session_name.as_deref(),
+            role_session_duration_secs,
+        )?;
 
-        let config = S3ClientConfig::new()
+        let mut config = S3ClientConfig::new()
             /*
             TODO - Add version number here
              https://github.com/awslabs/mountpoint-s3/blob/73328cc64a2dbca78e879730d4d264aedd881c60/mountpoint-s3/src/main.rs#L427
@@ -57,6 +95,9 @@ impl MountpointS3Client {
             .part_size(part_size)
             .auth_config(auth_config)
             .endpoint_config(endpoint_config);
+        if let Some(max_attempts) = max_attempts {
+            config = config.max_attempts(max_attempts as usize);
+        }
         let crt_client = Arc::new(S3CrtClient::new(config).map_err(python_exception)?);
 
         Ok(MountpointS3Client::new(
@@ -65,6 +106,13 @@ impl MountpointS3Client {
             part_size,
             profile,
             no_sign_request,
+            endpoint_url,
+            force_path_style,
+            role_arn,
+            external_id,
+            session_name,
+            role_session_duration_secs,
+            max_attempts,
             crt_client,
         ))
     }
@@ -88,15 +136,21 @@ impl MountpointS3Client {
         ListObjectStream::new(self.client.clone(), bucket, prefix, delimiter, max_keys)
     }
 
-    #[pyo3(signature = (bucket, key, storage_class=None))]
+    #[pyo3(signature = (bucket, key, storage_class=None, sse=None, sse_kms_key_id=None))]
     pub fn put_object(
         slf: PyRef<'_, Self>,
         bucket: String,
         key: String,
         storage_class: Option<String>,
+        sse: Option<String>,
+        sse_kms_key_id: Option<String>,
     ) -> PyResult<PutObjectStream> {
+        validate_sse(sse.as_deref(), sse_kms_key_id.as_deref())?;
+
         let mut params = PutObjectParams::default();
         params.storage_class = storage_class;
+        params.server_side_encryption = sse;
+        params.ssekms_key_id = sse_kms_key_id;
 
         slf.client.put_object(slf.py(), bucket, key, params)
     }
@@ -109,18 +163,33 @@ impl MountpointS3Client {
             slf.part_size.to_object(py),
             slf.profile.to_object(py),
             slf.no_sign_request.to_object(py),
+            slf.endpoint_url.to_object(py),
+            slf.force_path_style.to_object(py),
+            slf.role_arn.to_object(py),
+            slf.external_id.to_object(py),
+            slf.session_name.to_object(py),
+            slf.role_session_duration_secs.to_object(py),
+            slf.max_attempts.to_object(py),
         ];
         Ok(PyTuple::new(py, state))
     }
 }
 
 impl MountpointS3Client {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new<Client: ObjectClient>(
         region: String,
         throughput_target_gbps: f64,
         part_size: usize,
         profile: Option<String>,
         no_sign_request: bool,
+        endpoint_url: Option<String>,
+        force_path_style: bool,
+        role_arn: Option<String>,
+        external_id: Option<String>,
+        session_name: Option<String>,
+        role_session_duration_secs: Option<u64>,
+        max_attempts: Option<u32>,
         client: Arc<Client>,
     ) -> Self
     where
@@ -134,17 +203,159 @@ impl MountpointS3Client {
             region,
             profile,
             no_sign_request,
+            endpoint_url,
+            force_path_style,
+            role_arn,
+            external_id,
+            session_name,
+            role_session_duration_secs,
+            max_attempts,
             client: Arc::new(MountpointS3ClientInnerImpl::new(client)),
         }
     }
 }
 
-fn auth_config(profile: Option<&str>, no_sign_request: bool) -> S3ClientAuthConfig {
+/// Reject server-side-encryption arguments S3 would ignore or error on: `sse` must name a
+/// supported algorithm, and a KMS key id only makes sense with `aws:kms`.
+fn validate_sse(sse: Option<&str>, sse_kms_key_id: Option<&str>) -> PyResult<()> {
+    match sse {
+        None | Some("AES256") | Some("aws:kms") => {}
+        Some(other) => {
+            return Err(PyValueError::new_err(format!(
+                "sse must be \"AES256\" or \"aws:kms\", got {other:?}"
+            )))
+        }
+    }
+    if sse_kms_key_id.is_some() && sse != Some("aws:kms") {
+        return Err(PyValueError::new_err(
+            "sse_kms_key_id is only valid when sse is \"aws:kms\"",
+        ));
+    }
+    Ok(())
+}
+
+fn endpoint_config(
+    region: &str,
+    endpoint_url: Option<&str>,
+    force_path_style: bool,
+) -> PyResult<EndpointConfig> {
+    let mut endpoint_config = EndpointConfig::new(region);
+    if let Some(endpoint_url) = endpoint_url {
+        let allocator = Allocator::default();
+        let uri = Uri::new_from_str(&allocator, endpoint_url).map_err(python_exception)?;
+        endpoint_config = endpoint_config.endpoint(uri);
+    }
+    if force_path_style {
+        endpoint_config = endpoint_config.addressing_style(AddressingStyle::Path);
+    }
+    Ok(endpoint_config)
+}
+
+fn auth_config(
+    profile: Option<&str>,
+    no_sign_request: bool,
+    role_arn: Option<&str>,
+    external_id: Option<&str>,
+    session_name: Option<&str>,
+    session_duration_secs: Option<u64>,
+) -> PyResult<S3ClientAuthConfig> {
     if no_sign_request {
-        S3ClientAuthConfig::NoSigning
+        Ok(S3ClientAuthConfig::NoSigning)
+    } else if let Some(role_arn) = role_arn {
+        Ok(S3ClientAuthConfig::Provider(assume_role_provider(
+            role_arn,
+            external_id,
+            session_name,
+            session_duration_secs,
+        )?))
     } else if let Some(profile_name) = profile {
-        S3ClientAuthConfig::Profile(profile_name.to_string())
+        Ok(S3ClientAuthConfig::Profile(profile_name.to_string()))
     } else {
-        S3ClientAuthConfig::Default
+        Ok(S3ClientAuthConfig::Default)
+    }
+}
+
+/// Build an STS assume-role credentials provider that sources its base identity from the
+/// default credentials chain and automatically refreshes the assumed-role credentials when
+/// they approach expiry.
+fn assume_role_provider(
+    role_arn: &str,
+    external_id: Option<&str>,
+    session_name: Option<&str>,
+    session_duration_secs: Option<u64>,
+) -> PyResult<CredentialsProvider> {
+    let session_name = session_name.unwrap_or("s3dataset-assume-role");
+    let duration_seconds = match session_duration_secs {
+        Some(secs) => Some(u16::try_from(secs).map_err(|_| {
+            PyValueError::new_err("role_session_duration_secs is too large for an STS session")
+        })?),
+        None => None,
+    };
+
+    // The S3 client's own bootstrap isn't reachable at config-build time, so the credentials
+    // provider gets a dedicated CRT I/O stack. Build it once here and share a single
+    // allocator across the bootstrap, the base credentials chain, and the STS provider.
+    let allocator = Allocator::default();
+    let event_loop_group = EventLoopGroup::new_default(&allocator, None, None).map_err(python_exception)?;
+    let resolver_options = HostResolverDefaultOptions {
+        max_entries: 8,
+        event_loop_group: &event_loop_group,
+    };
+    let host_resolver = HostResolver::new_default(&allocator, &resolver_options).map_err(python_exception)?;
+    let bootstrap_options = ClientBootstrapOptions {
+        event_loop_group: &event_loop_group,
+        host_resolver: &host_resolver,
+    };
+    let client_bootstrap = ClientBootstrap::new(&allocator, &bootstrap_options).map_err(python_exception)?;
+
+    // STS is reached over TLS; the assume-role provider needs a TLS context for its endpoint.
+    let tls_ctx = TlsContext::new(&allocator, &TlsContextOptions::new_client(&allocator).map_err(python_exception)?)
+        .map_err(python_exception)?;
+
+    // The base identity for the assume-role call comes from the default credentials chain
+    // (environment, instance/container role, or AWS_PROFILE). The `profile` argument is not
+    // used when `role_arn` is set; only AWS_PROFILE in the environment selects a profile here.
+    let base_options = CredentialsProviderChainDefaultOptions {
+        bootstrap: &client_bootstrap,
+    };
+    let base_provider =
+        CredentialsProvider::new_chain_default(&allocator, base_options).map_err(python_exception)?;
+
+    let sts_options = CredentialsProviderStsOptions {
+        bootstrap: &client_bootstrap,
+        tls_ctx: &tls_ctx,
+        creds_provider: base_provider,
+        role_arn: role_arn.to_string(),
+        session_name: session_name.to_string(),
+        external_id: external_id.map(|id| id.to_string()),
+        // When unset the CRT uses its default session duration (900s STS minimum); the
+        // provider refreshes the assumed-role credentials automatically once they cross the
+        // expiry threshold.
+        duration_seconds,
+    };
+    CredentialsProvider::new_sts(&allocator, sts_options).map_err(python_exception)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_sse_accepts_supported_algorithms() {
+        assert!(validate_sse(None, None).is_ok());
+        assert!(validate_sse(Some("AES256"), None).is_ok());
+        assert!(validate_sse(Some("aws:kms"), None).is_ok());
+        assert!(validate_sse(Some("aws:kms"), Some("arn:aws:kms:...:key/abc")).is_ok());
+    }
+
+    #[test]
+    fn validate_sse_rejects_unknown_algorithm() {
+        assert!(validate_sse(Some("rot13"), None).is_err());
+    }
+
+    #[test]
+    fn validate_sse_rejects_kms_key_without_kms() {
+        assert!(validate_sse(None, Some("arn:aws:kms:...:key/abc")).is_err());
+        assert!(validate_sse(Some("AES256"), Some("arn:aws:kms:...:key/abc")).is_err());
     }
 }
\ No newline at end of file